@@ -1,8 +1,13 @@
 use super::instruction_hooking::{hook_instruction, unhook_instruction};
+use std::collections::HashMap;
 use std::error::Error;
 use std::io::{Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 use std::{
 	net::{SocketAddr, TcpListener, TcpStream},
 	thread::JoinHandle,
@@ -19,41 +24,195 @@ use dm::*;
 // connection: a TcpStream sent from the ServerThread for the Server to send responses on
 // requests: requests from the debug-client for the Server to handle
 //
-// Limitations: only ever accepts one connection & doesn't fully stop processing once that connection dies
+// The ServerThread keeps accepting connections for the life of the server; each one
+// gets its own reader thread and an entry in Server::connections, and requests are
+// tagged with their origin so responses route back to the right client. Stop/output
+// events are broadcast to everyone attached.
 //
 
+// How messages are delimited on the socket.
+//
+// The original auxtools client speaks a bespoke protocol where JSON objects are
+// separated by null bytes. DAP clients (VS Code, Helix) frame every message with a
+// `Content-Length: <n>\r\n\r\n<json>` header. We sniff the first bytes a client sends
+// to decide which one we're talking to, so both keep working.
+//
+// Note this only adopts DAP's *framing*. The JSON payload inside each frame is still
+// auxtools' native `Request`/`ResponseMessage` shape, not DAP `request`/`response`/
+// `event` objects (`type`, `command`, `request_seq`, `body`, `success`), so a stock
+// VS Code/Helix client still needs a thin adapter to translate the bodies; what this
+// buys is that the transport layer no longer needs a custom shim.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Framing {
+	NullTerminated,
+	ContentLength,
+}
+
+impl Framing {
+	const HEADER: &'static [u8] = b"Content-Length";
+
+	// Decide the framing from the bytes buffered so far, or `None` while the prefix is
+	// still ambiguous. A buffer that equals or extends the `Content-Length` header is
+	// Content-Length framing; the moment the prefix diverges from that header it must be
+	// a legacy null-terminated message (whose first byte is `"` or `{`, never `C`). Only
+	// a proper prefix of the header is still undecided, so we keep reading just long
+	// enough to tell the two apart instead of waiting for a fixed byte count.
+	fn detect(data: &[u8]) -> Option<Framing> {
+		if data.starts_with(Self::HEADER) {
+			Some(Framing::ContentLength)
+		} else if Self::HEADER.starts_with(data) {
+			None
+		} else {
+			Some(Framing::NullTerminated)
+		}
+	}
+}
+
+// A hit-count predicate parsed from the string a client sends with a breakpoint.
+// Mirrors the handful of forms DAP clients emit for `hitCondition`.
+enum HitCondition {
+	GreaterThan(u64),
+	GreaterOrEqual(u64),
+	LessThan(u64),
+	Equal(u64),
+	Multiple(u64),
+}
+
+impl HitCondition {
+	fn parse(source: &str) -> Option<HitCondition> {
+		let source = source.trim();
+
+		if let Some(rest) = source.strip_prefix(">=") {
+			Some(HitCondition::GreaterOrEqual(rest.trim().parse().ok()?))
+		} else if let Some(rest) = source.strip_prefix('>') {
+			Some(HitCondition::GreaterThan(rest.trim().parse().ok()?))
+		} else if let Some(rest) = source.strip_prefix('<') {
+			Some(HitCondition::LessThan(rest.trim().parse().ok()?))
+		} else if let Some(rest) = source.strip_prefix("==") {
+			Some(HitCondition::Equal(rest.trim().parse().ok()?))
+		} else if let Some(rest) = source.strip_prefix('%') {
+			Some(HitCondition::Multiple(rest.trim().parse().ok()?))
+		} else {
+			// A bare number behaves like `==n`, matching VS Code's default.
+			Some(HitCondition::Equal(source.parse().ok()?))
+		}
+	}
+
+	fn is_satisfied(&self, hits: u64) -> bool {
+		match *self {
+			HitCondition::GreaterThan(n) => hits > n,
+			HitCondition::GreaterOrEqual(n) => hits >= n,
+			HitCondition::LessThan(n) => hits < n,
+			HitCondition::Equal(n) => hits == n,
+			HitCondition::Multiple(n) => n != 0 && hits % n == 0,
+		}
+	}
+}
+
+// Bookkeeping the server keeps for a hooked instruction so `handle_breakpoint` can
+// decide whether a hit should actually pause.
+#[derive(Default)]
+struct Breakpoint {
+	condition: Option<String>,
+	hit_condition: Option<HitCondition>,
+	log_message: Option<String>,
+	hits: u64,
+}
+
+// Whether a breakpoint hit should pause the world or be swallowed.
+enum BreakpointAction {
+	Stop,
+	Resume,
+}
+
+type ConnectionId = u32;
+
+// One attached debug client's send side, plus how it wants messages framed.
+struct Connection {
+	stream: TcpStream,
+	framing: Framing,
+}
+
+// A request plus the routing the networking thread tagged it with: which client it
+// came from, and a globally-unique sequence number used to correlate the response.
+struct IncomingRequest {
+	connection_id: ConnectionId,
+	seq: u64,
+	request: Request,
+}
+
 pub struct Server {
-	connection: mpsc::Receiver<TcpStream>,
-	requests: mpsc::Receiver<Request>,
+	connection: mpsc::Receiver<(ConnectionId, TcpStream, Framing)>,
+	// Ids of connections whose reader thread has exited (EOF or socket error). Draining
+	// this is how we learn a client has gone away even while paused with nothing to send.
+	disconnects: mpsc::Receiver<ConnectionId>,
+	requests: mpsc::Receiver<IncomingRequest>,
 	stacks: Option<CallStacks>,
-	stream: Option<TcpStream>,
+	connections: HashMap<ConnectionId, Connection>,
+	// seq -> originating connection, so a response finds its way back to the right
+	// socket even with several clients attached at once.
+	inflight: HashMap<u64, ConnectionId>,
+	// The request currently being serviced, so `send_or_disconnect` knows who to reply
+	// to. `None` while we're emitting an unsolicited event (broadcast instead).
+	serving: Option<u64>,
+	breakpoints: HashMap<InstructionRef, Breakpoint>,
+	// How long the pause loop blocks before doing periodic work. `None` means block
+	// forever waiting for the next request (the original behavior).
+	pause_timeout: Option<Duration>,
+	// Output telemetry accrued while running, flushed to clients on the next tick or
+	// pause-loop wake-up.
+	pending_output: Vec<String>,
 	_thread: JoinHandle<()>,
 }
 
 struct ServerThread {
-	connection: mpsc::Sender<TcpStream>,
-	requests: mpsc::Sender<Request>,
+	connection: mpsc::Sender<(ConnectionId, TcpStream, Framing)>,
+	disconnect: mpsc::Sender<ConnectionId>,
+	requests: mpsc::Sender<IncomingRequest>,
 	listener: TcpListener,
-	stream: Option<TcpStream>,
 }
 
+// Per-connection reader: owns one client's socket and pumps its framed requests onto
+// the shared request channel, tagging each with the connection id and a sequence.
+struct ConnectionReader {
+	connection_id: ConnectionId,
+	next_seq: Arc<AtomicU64>,
+	connection: mpsc::Sender<(ConnectionId, TcpStream, Framing)>,
+	disconnect: mpsc::Sender<ConnectionId>,
+	requests: mpsc::Sender<IncomingRequest>,
+	stream: TcpStream,
+}
+
+// How long the pause loop blocks on a single `recv` before doing periodic work
+// (flushing output, pruning vanished clients). Non-zero by default so a client that
+// disappears while the world is paused can't wedge `handle_breakpoint` forever; an
+// operator can still opt into block-forever via `Configure { pause_timeout_ms: None }`.
+const DEFAULT_PAUSE_TIMEOUT: Duration = Duration::from_millis(250);
+
 impl Server {
 	pub fn listen(addr: &SocketAddr) -> std::io::Result<Server> {
 		let (connection_sender, connection_receiver) = mpsc::channel();
+		let (disconnect_sender, disconnect_receiver) = mpsc::channel();
 		let (requests_sender, requests_receiver) = mpsc::channel();
 
 		let thread = ServerThread {
 			connection: connection_sender,
+			disconnect: disconnect_sender,
 			requests: requests_sender,
 			listener: TcpListener::bind(addr)?,
-			stream: None,
 		};
 
 		Ok(Server {
 			connection: connection_receiver,
+			disconnects: disconnect_receiver,
 			requests: requests_receiver,
 			stacks: None,
-			stream: None,
+			connections: HashMap::new(),
+			inflight: HashMap::new(),
+			serving: None,
+			breakpoints: HashMap::new(),
+			pause_timeout: Some(DEFAULT_PAUSE_TIMEOUT),
+			pending_output: vec![],
 			_thread: thread.start_thread(),
 		})
 	}
@@ -90,25 +249,57 @@ impl Server {
 	}
 
 	fn value_to_variable(name: String, value: &Value) -> Result<Variable, Runtime> {
+		// Hand back a fresh handle for anything the client could drill into (a list, or
+		// a datum/atom with a `vars` table) so it can issue a follow-up `Variables`
+		// request; leave scalars un-expandable.
+		let variables = if value_is_expandable(value) {
+			Some(unsafe {
+				VariablesRef::Internal {
+					tag: value.value.tag as u8,
+					data: value.value.data.id,
+				}
+			})
+		} else {
+			None
+		};
+
 		Ok(Variable {
 			name,
-			kind: "TODO".to_owned(),
+			kind: value_kind(value),
 			value: format!("{:?}", value),
-			variables: None,
+			variables,
 		})
 	}
 
-	fn value_to_variables(value: &Value) -> Result<Vec<Variable>, Runtime> {
+	// Expand a list into its elements, or anything else into its `vars` table, but only
+	// materialize the `[start, start + count)` window so expanding `world` or a 100k
+	// entry list doesn't stall the main thread.
+	fn value_to_variables(
+		value: &Value,
+		start: Option<u32>,
+		count: Option<u32>,
+	) -> Result<Vec<Variable>, Runtime> {
 		let mut variables = vec![];
+		let start = start.unwrap_or(0);
+
+		if unsafe { value.value.tag } == raw_types::values::ValueTag::List {
+			let list = List::from_value(value)?;
+			let len = list.len();
+			// Clamp the window to the list so an out-of-range `start`/`count` yields an
+			// empty slice rather than overflowing or panicking on the range.
+			let start = start.min(len);
+			let end = count
+				.map(|count| start.saturating_add(count).min(len))
+				.unwrap_or(len);
+
+			// BYOND lists are 1-indexed.
+			for i in (start + 1)..=end {
+				let element = list.get(i)?;
+				variables.push(Self::value_to_variable(format!("[{}]", i), &element)?);
+			}
 
-		/*
-		let vars = value.get_list("vars")?;
-		for i in 1..=vars.len() {
-			let name = vars.get(i)?.as_string()?;
-			let value = value.get(name.as_str())?;
-			variables.push(Self::value_to_variable(name, &value)?);
+			return Ok(variables);
 		}
-		*/
 
 		let vars = unsafe {
 			if value.value.tag == raw_types::values::ValueTag::World && value.value.data.id == 1 {
@@ -122,8 +313,13 @@ impl Server {
 		};
 
 		let vars = List::from_value(&vars)?;
+		let len = vars.len();
+		let start = start.min(len);
+		let end = count
+			.map(|count| start.saturating_add(count).min(len))
+			.unwrap_or(len);
 
-		for i in 1..=vars.len() {
+		for i in (start + 1)..=end {
 			let name = vars.get(i)?.as_string()?;
 			let value = value.get(name.as_str())?;
 			variables.push(Self::value_to_variable(name, &value)?);
@@ -135,13 +331,45 @@ impl Server {
 	// returns true if we need to break
 	fn handle_request(&mut self, request: Request) -> bool {
 		match request {
-			Request::BreakpointSet { instruction } => {
+			Request::Initialize => {
+				self.send_or_disconnect(Response::Initialized {
+					capabilities: Capabilities {
+						// Without BYOND's compiler we can only resolve bare variable paths,
+						// not the arbitrary DM expressions a client would send as a condition
+						// (`health < 50`), so we don't claim full conditional-breakpoint
+						// support. Hit counts and logpoints are unaffected, and hovers over a
+						// single identifier still resolve.
+						supports_conditional_breakpoints: false,
+						supports_hit_conditional_breakpoints: true,
+						supports_log_points: true,
+						supports_evaluate_for_hovers: true,
+						supports_set_variable: false,
+					},
+				});
+			}
+
+			Request::BreakpointSet {
+				instruction,
+				condition,
+				hit_condition,
+				log_message,
+			} => {
 				let line = self.get_line_number(instruction.proc.clone(), instruction.offset);
 
 				// TODO: better error handling
-				match dm::Proc::find_override(instruction.proc.path, instruction.proc.override_id) {
+				match dm::Proc::find_override(instruction.proc.path.clone(), instruction.proc.override_id) {
 					Some(proc) => match hook_instruction(&proc, instruction.offset) {
 						Ok(()) => {
+							self.breakpoints.insert(
+								Self::breakpoint_key(&instruction),
+								Breakpoint {
+									condition,
+									hit_condition: hit_condition.as_deref().and_then(HitCondition::parse),
+									log_message,
+									hits: 0,
+								},
+							);
+
 							self.send_or_disconnect(Response::BreakpointSet {
 								result: BreakpointSetResult::Success { line },
 							});
@@ -163,6 +391,8 @@ impl Server {
 			}
 
 			Request::BreakpointUnset { instruction } => {
+				self.breakpoints.remove(&Self::breakpoint_key(&instruction));
+
 				match dm::Proc::find_override(instruction.proc.path, instruction.proc.override_id) {
 					Some(proc) => match unhook_instruction(&proc, instruction.offset) {
 						Ok(()) => {
@@ -221,41 +451,61 @@ impl Server {
 				start_frame,
 				count,
 			} => {
-				assert_eq!(thread_id, 0);
-
 				self.send_or_disconnect(match &self.stacks {
 					Some(stacks) => {
-						let stack = &stacks.active;
-						let start_frame = start_frame.unwrap_or(0);
-						let end_frame = start_frame + count.unwrap_or(stack.len() as u32);
-
-						let start_frame = start_frame as usize;
-						let end_frame = end_frame as usize;
+						// thread 0 is the context that hit the breakpoint; higher ids are
+						// the suspended (sleeping/spawned) contexts, in order.
+						let stack = if thread_id == 0 {
+							Some(&stacks.active)
+						} else {
+							stacks.suspended.get((thread_id - 1) as usize)
+						};
 
-						let mut frames = vec![];
+						match stack {
+							Some(stack) => {
+								let start_frame = start_frame.unwrap_or(0);
+								let end_frame = start_frame + count.unwrap_or(stack.len() as u32);
+
+								let start_frame = start_frame as usize;
+								let end_frame = end_frame as usize;
+
+								let mut frames = vec![];
+
+								for i in start_frame..end_frame {
+									if i >= stack.len() {
+										break;
+									}
+
+									let proc_ref = ProcRef {
+										path: stack[i].proc.path.to_owned(),
+										override_id: 0,
+									};
+
+									frames.push(StackFrame {
+										instruction: InstructionRef {
+											proc: proc_ref.clone(),
+											offset: stack[i].offset as u32,
+										},
+										line: self.get_line_number(proc_ref, stack[i].offset as u32),
+									});
+								}
 
-						for i in start_frame..end_frame {
-							if i >= stack.len() {
-								break;
+								Response::StackFrames {
+									frames,
+									total_count: stack.len() as u32,
+								}
 							}
 
-							let proc_ref = ProcRef {
-								path: stack[i].proc.path.to_owned(),
-								override_id: 0,
-							};
-
-							frames.push(StackFrame {
-								instruction: InstructionRef {
-									proc: proc_ref.clone(),
-									offset: stack[i].offset as u32,
-								},
-								line: self.get_line_number(proc_ref, stack[i].offset as u32),
-							});
-						}
-
-						Response::StackFrames {
-							frames,
-							total_count: stack.len() as u32,
+							None => {
+								eprintln!(
+									"Debug server received StackFrames request for invalid thread_id ({})",
+									thread_id
+								);
+								Response::StackFrames {
+									frames: vec![],
+									total_count: 0,
+								}
+							}
 						}
 					}
 
@@ -269,51 +519,94 @@ impl Server {
 				});
 			}
 
-			Request::Scopes { frame_id } => self.send_or_disconnect(match &self.stacks {
-				Some(stacks) => match stacks.active.get(frame_id as usize) {
-					Some(frame) => {
-						let mut arguments = None;
-						let mut locals = None;
+			Request::Threads => {
+				let threads = match &self.stacks {
+					Some(stacks) => {
+						// Name a context after the proc sitting on top of its stack.
+						let name_of = |stack: &[_], id: u32| match stack.first() {
+							Some(frame) => format!(
+								"{} [{}]",
+								frame.proc.path,
+								if id == 0 { "active" } else { "suspended" }
+							),
+							None => format!("thread {}", id),
+						};
 
-						if !frame.args.is_empty() {
-							arguments = Some(VariablesRef::Arguments {
-								frame: frame_id as u16,
-							});
-						}
+						let mut threads = vec![ThreadInfo {
+							id: 0,
+							name: name_of(&stacks.active, 0),
+						}];
 
-						if !frame.locals.is_empty() {
-							locals = Some(VariablesRef::Locals {
-								frame: frame_id as u16,
+						for (i, stack) in stacks.suspended.iter().enumerate() {
+							let id = (i + 1) as u32;
+							threads.push(ThreadInfo {
+								id,
+								name: name_of(stack, id),
 							});
 						}
 
-						let globals_value = Value::globals();
-						let globals = unsafe {
-							VariablesRef::Internal {
-								tag: globals_value.value.tag as u8,
-								data: globals_value.value.data.id,
+						threads
+					}
+
+					None => vec![],
+				};
+
+				self.send_or_disconnect(Response::Threads { threads });
+			}
+
+			Request::Scopes { thread_id, frame_id } => self.send_or_disconnect(match &self.stacks {
+				Some(stacks) => {
+					let stack = if thread_id == 0 {
+						Some(&stacks.active)
+					} else {
+						stacks.suspended.get((thread_id - 1) as usize)
+					};
+
+					match stack.and_then(|stack| stack.get(frame_id as usize)) {
+						Some(frame) => {
+							let mut arguments = None;
+							let mut locals = None;
+
+							if !frame.args.is_empty() {
+								arguments = Some(VariablesRef::Arguments {
+									frame: frame_id as u16,
+								});
+							}
+
+							if !frame.locals.is_empty() {
+								locals = Some(VariablesRef::Locals {
+									frame: frame_id as u16,
+								});
 							}
-						};
 
-						Response::Scopes {
-							arguments: arguments,
-							locals: locals,
-							globals: Some(globals),
+							let globals_value = Value::globals();
+							let globals = unsafe {
+								VariablesRef::Internal {
+									tag: globals_value.value.tag as u8,
+									data: globals_value.value.data.id,
+								}
+							};
+
+							Response::Scopes {
+								arguments: arguments,
+								locals: locals,
+								globals: Some(globals),
+							}
 						}
-					}
 
-					None => {
-						eprintln!(
-							"Debug server received Scopes request for invalid frame_id ({})",
-							frame_id
-						);
-						Response::Scopes {
-							arguments: None,
-							locals: None,
-							globals: None,
+						None => {
+							eprintln!(
+								"Debug server received Scopes request for invalid frame_id ({})",
+								frame_id
+							);
+							Response::Scopes {
+								arguments: None,
+								locals: None,
+								globals: None,
+							}
 						}
 					}
-				},
+				}
 
 				None => {
 					eprintln!("Debug server received Scopes request when not paused");
@@ -325,7 +618,7 @@ impl Server {
 				}
 			}),
 
-			Request::Variables { vars } => {
+			Request::Variables { vars, start, count } => {
 				let response = match vars {
 					VariablesRef::Internal { tag, data } => {
 						let value = unsafe {
@@ -335,7 +628,7 @@ impl Server {
 							})
 						};
 
-						match Self::value_to_variables(&value) {
+						match Self::value_to_variables(&value, start, count) {
 							Ok(vars) => Response::Variables { vars },
 
 							Err(e) => {
@@ -351,6 +644,44 @@ impl Server {
 				self.send_or_disconnect(response);
 			}
 
+			// Scoped to variable inspection: `expression` is resolved as a dotted scope
+			// path (see `eval_in_frame`), not compiled as DM, so calls/operators/indexing
+			// aren't supported until a runtime evaluator is exposed. Watch and hover over
+			// a variable or field work; general REPL expressions report an error.
+			Request::Evaluate {
+				frame_id,
+				expression,
+				context: _,
+			} => {
+				let response = match self.eval_in_frame(frame_id as usize, &expression) {
+					Ok(value) => match Self::value_to_variable(String::new(), &value) {
+						Ok(variable) => Response::Evaluate {
+							value: variable.value,
+							variables_ref: variable.variables,
+						},
+
+						Err(e) => {
+							eprintln!("Debug server hit a runtime when processing Evaluate request: {:?}", e);
+							Response::Evaluate {
+								value: format!("{:?}", value),
+								variables_ref: None,
+							}
+						}
+					},
+
+					Err(e) => Response::Evaluate {
+						value: format!("runtime error: {}", e),
+						variables_ref: None,
+					},
+				};
+
+				self.send_or_disconnect(response);
+			}
+
+			Request::Configure { pause_timeout_ms } => {
+				self.pause_timeout = pause_timeout_ms.map(Duration::from_millis);
+			}
+
 			Request::Continue { .. } => {
 				eprintln!("Debug server received a continue request when not paused. Ignoring.");
 			}
@@ -363,6 +694,169 @@ impl Server {
 		false
 	}
 
+	// Canonical key for the breakpoint-state map. We can only report and reconstruct
+	// override 0 (`StackFrames` and `current_instruction` both do), so the map is keyed
+	// on (path, offset) with `override_id` normalized to 0 on insert, unset and lookup
+	// alike. That keeps the store and the runtime lookup on one convention, so a
+	// condition/hit-count/logpoint set against any override still applies when the
+	// breakpoint fires instead of being stranded under the client's original id.
+	fn breakpoint_key(instruction: &InstructionRef) -> InstructionRef {
+		InstructionRef {
+			proc: ProcRef {
+				path: instruction.proc.path.clone(),
+				override_id: 0,
+			},
+			offset: instruction.offset,
+		}
+	}
+
+	// The instruction the top active frame is currently sitting on, used to look up any
+	// condition/hit-count/logpoint state attached to the breakpoint that fired.
+	fn current_instruction(&self) -> Option<InstructionRef> {
+		let frame = self.stacks.as_ref()?.active.first()?;
+		Some(Self::breakpoint_key(&InstructionRef {
+			proc: ProcRef {
+				path: frame.proc.path.to_owned(),
+				override_id: 0,
+			},
+			offset: frame.offset as u32,
+		}))
+	}
+
+	// Evaluate an `expression` in the scope of the given stack frame.
+	//
+	// BYOND's compiler isn't exposed, so we can't compile arbitrary DM at runtime;
+	// instead we resolve a dotted variable path (`health`, `src.loc.name`, `usr`,
+	// `global.vars`, ...) against the frame's scope using the same value-access
+	// machinery the rest of the debugger relies on. The leading segment is matched
+	// against the scope keywords (`src`, `usr`, `global`), then the frame's locals, then
+	// its arguments, then the vars on `src`; each further segment is a field access via
+	// `Value::get`. That covers variable/field watches, and conditions and logpoints that
+	// name a single variable path; anything with a call, operator or index is an error.
+	fn eval_in_frame(&self, frame_id: usize, expression: &str) -> Result<Value, Runtime> {
+		let frame = self
+			.stacks
+			.as_ref()
+			.and_then(|stacks| stacks.active.get(frame_id))
+			.ok_or_else(|| dm::runtime!("no frame {} to evaluate in", frame_id))?;
+
+		let expression = expression.trim();
+		let mut segments = expression.split('.').map(str::trim);
+
+		let root = segments
+			.next()
+			.filter(|segment| !segment.is_empty())
+			.ok_or_else(|| dm::runtime!("empty expression"))?;
+
+		let mut value = match root {
+			"src" => frame.src.clone(),
+			"usr" => frame.usr.clone(),
+			"global" | "globals" => Value::globals(),
+
+			name => {
+				let mut resolved = None;
+
+				for (local_name, local_value) in &frame.locals {
+					if local_name.to_string() == name {
+						resolved = Some(local_value.clone());
+						break;
+					}
+				}
+
+				if resolved.is_none() {
+					for (arg_name, arg_value) in &frame.args {
+						if arg_name.as_ref().map(|arg_name| arg_name.to_string()).as_deref() == Some(name) {
+							resolved = Some(arg_value.clone());
+							break;
+						}
+					}
+				}
+
+				// Fall back to a variable on `src`.
+				match resolved {
+					Some(value) => value,
+					None => frame.src.get(name)?,
+				}
+			}
+		};
+
+		for segment in segments {
+			if segment.is_empty() {
+				return Err(dm::runtime!("malformed expression `{}`", expression));
+			}
+
+			value = value.get(segment)?;
+		}
+
+		Ok(value)
+	}
+
+	// Expand every `{expr}` in a logpoint message, evaluating each expression against
+	// the top frame.
+	fn interpolate(&self, message: &str) -> String {
+		interpolate_with(message, |expression| match self.eval_in_frame(0, expression) {
+			Ok(value) => format!("{:?}", value),
+			Err(e) => format!("<{}>", e),
+		})
+	}
+
+	// Apply any condition, hit-count predicate and logpoint attached to the breakpoint
+	// that just fired, deciding whether it should actually pause the world.
+	fn evaluate_breakpoint(&mut self) -> BreakpointAction {
+		let instruction = match self.current_instruction() {
+			Some(instruction) => instruction,
+			None => return BreakpointAction::Stop,
+		};
+
+		// A plain breakpoint with no extra state always stops.
+		if !self.breakpoints.contains_key(&instruction) {
+			return BreakpointAction::Stop;
+		}
+
+		// Test the condition before touching the hit counter, so a false condition
+		// doesn't count as a hit (matching DAP semantics).
+		let condition = self.breakpoints[&instruction].condition.clone();
+		if let Some(condition) = condition {
+			match self.eval_in_frame(0, &condition) {
+				Ok(value) if !value_is_truthy(&value) => return BreakpointAction::Resume,
+				Ok(_) => {}
+				// We resolve conditions as bare variable paths only, so anything we can't
+				// evaluate is treated as "not met" and resumes rather than silently
+				// becoming an unconditional stop. Surface the failure so it isn't lost.
+				Err(e) => {
+					self.pending_output
+						.push(format!("[debugger] condition `{}` errored: {}\n", condition, e));
+					return BreakpointAction::Resume;
+				}
+			}
+		}
+
+		let hits = {
+			let breakpoint = self.breakpoints.get_mut(&instruction).unwrap();
+			breakpoint.hits += 1;
+			breakpoint.hits
+		};
+
+		// A logpoint never stops; it just emits its (interpolated) message.
+		let log_message = self.breakpoints[&instruction].log_message.clone();
+		if let Some(log_message) = log_message {
+			let output = self.interpolate(&log_message);
+			self.pending_output.push(format!("{}\n", output));
+			return BreakpointAction::Resume;
+		}
+
+		let satisfied = match &self.breakpoints[&instruction].hit_condition {
+			Some(hit_condition) => hit_condition.is_satisfied(hits),
+			None => true,
+		};
+
+		if satisfied {
+			BreakpointAction::Stop
+		} else {
+			BreakpointAction::Resume
+		}
+	}
+
 	pub fn handle_breakpoint(
 		&mut self,
 		_ctx: *mut raw_types::procs::ExecutionContext,
@@ -372,92 +866,329 @@ impl Server {
 		// TODO: it'd be cool if all this data was fetched lazily
 		self.stacks = Some(CallStacks::new(&DMContext {}));
 
-		self.send_or_disconnect(Response::BreakpointHit { reason });
-
-		while let Ok(request) = self.requests.recv() {
-			// Hijack and handle any Continue requests
-			if let Request::Continue { kind } = request {
+		// Only instruction breakpoints carry conditions/logpoints; explicit pauses and
+		// single-steps always stop.
+		if let BreakpointReason::Breakpoint = reason {
+			if let BreakpointAction::Resume = self.evaluate_breakpoint() {
 				self.stacks = None;
-				return kind;
+				return ContinueKind::Continue;
 			}
+		}
+
+		// A stop event is unsolicited, so every attached client hears about it.
+		self.broadcast(Response::BreakpointHit {
+			reason: reason.clone(),
+		});
+		self.flush_output();
+
+		loop {
+			// A client that attaches while we're paused never saw the stop event, so
+			// catch each newcomer up before we might act on an emptied connection set.
+			self.catch_up_new_clients(&reason);
+
+			// Wait for the next request, but only up to `pause_timeout` so we can do
+			// periodic work (flush telemetry, prune dead clients) while paused instead
+			// of wedging forever on a vanished client.
+			let incoming = match self.pause_timeout {
+				Some(timeout) => match self.requests.recv_timeout(timeout) {
+					Ok(incoming) => Some(incoming),
+					Err(RecvTimeoutError::Timeout) => None,
+					Err(RecvTimeoutError::Disconnected) => break,
+				},
 
-			// if we get a pause request here we can ignore it
-			self.handle_request(request);
+				None => match self.requests.recv() {
+					Ok(incoming) => Some(incoming),
+					Err(_) => break,
+				},
+			};
+
+			match incoming {
+				Some(incoming) => {
+					// Hijack and handle any Continue requests - a resume from any client
+					// wakes the world back up for everyone.
+					if let Request::Continue { kind } = incoming.request {
+						self.stacks = None;
+						return kind;
+					}
+
+					// A client attaching mid-pause hands its stream to the Server on a
+					// separate channel from its requests; the stream can still be sitting in
+					// that channel when its very first request (e.g. `Initialize`) pops out
+					// here. Drain it in before dispatching so `send_or_disconnect` finds the
+					// connection instead of discarding the reply.
+					self.catch_up_new_clients(&reason);
+
+					// if we get a pause request here we can ignore it
+					self.dispatch(incoming);
+				}
+
+				// Timed out with nothing to do: flush telemetry and drop every client whose
+				// reader thread has reported its socket gone. That check is
+				// independent of whether any telemetry was queued, so a client that
+				// vanished silently - nothing in flight, no Continue coming - is still
+				// detected here. With nobody left attached there's no-one to resume us, so
+				// auto-resume rather than stay paused forever.
+				None => {
+					self.flush_output();
+					self.prune_dead_connections();
+
+					if self.connections.is_empty() {
+						self.stacks = None;
+						return ContinueKind::Continue;
+					}
+				}
+			}
 		}
 
-		// Client disappeared?
+		// All clients disappeared?
 		self.stacks = None;
 		ContinueKind::Continue
 	}
 
+	// Drain freshly-accepted connections and replay the current stop event to each, so a
+	// client that attaches while we're paused still learns the world is stopped.
+	fn catch_up_new_clients(&mut self, reason: &BreakpointReason) {
+		for connection_id in self.register_new_connections() {
+			self.send_to(
+				connection_id,
+				None,
+				Response::BreakpointHit {
+					reason: reason.clone(),
+				},
+			);
+		}
+	}
+
+	// Drain any freshly-accepted connections into the active set, returning the ids that
+	// were new so the caller can bring them up to date with whatever they missed.
+	fn register_new_connections(&mut self) -> Vec<ConnectionId> {
+		let mut new_connections = vec![];
+
+		while let Ok((connection_id, stream, framing)) = self.connection.try_recv() {
+			self.connections
+				.insert(connection_id, Connection { stream, framing });
+			new_connections.push(connection_id);
+		}
+
+		new_connections
+	}
+
 	// returns true if we need to pause
 	pub fn process(&mut self) -> bool {
-		// Don't do anything until we've got a stream
-		if self.stream.is_none() {
-			if let Ok(stream) = self.connection.try_recv() {
-				self.stream = Some(stream);
-			} else {
-				return false;
-			}
+		self.register_new_connections();
+		self.prune_dead_connections();
+
+		// Don't do anything until at least one client is attached.
+		if self.connections.is_empty() {
+			return false;
 		}
 
+		// Ship any telemetry that accrued since the last tick.
+		self.flush_output();
+
 		let mut should_pause = false;
 
-		while let Ok(request) = self.requests.try_recv() {
-			should_pause = should_pause || self.handle_request(request);
+		while let Ok(incoming) = self.requests.try_recv() {
+			should_pause = should_pause || self.dispatch(incoming);
 		}
 
 		should_pause
 	}
 
+	// Service one request, remembering where it came from so its response routes back
+	// to the originating client.
+	fn dispatch(&mut self, incoming: IncomingRequest) -> bool {
+		let IncomingRequest {
+			connection_id,
+			seq,
+			request,
+		} = incoming;
+
+		self.inflight.insert(seq, connection_id);
+		self.serving = Some(seq);
+
+		let should_pause = self.handle_request(request);
+
+		self.serving = None;
+		self.inflight.remove(&seq);
+
+		should_pause
+	}
+
+	// Reply to the client whose request we're currently servicing. Unsolicited events
+	// (no request in flight) are dropped here - use `broadcast` for those.
 	fn send_or_disconnect(&mut self, response: Response) {
-		if self.stream.is_none() {
+		let connection_id = match self.serving.and_then(|seq| self.inflight.get(&seq).copied()) {
+			Some(connection_id) => connection_id,
+			None => return,
+		};
+
+		self.send_to(connection_id, self.serving, response);
+	}
+
+	// Drain queued output telemetry to every attached client. Early-returns when nothing
+	// is queued, so this is NOT a reliable liveness poll on its own - see
+	// `prune_dead_connections` for that.
+	fn flush_output(&mut self) {
+		if self.pending_output.is_empty() {
 			return;
 		}
 
-		match self.send(response) {
-			Ok(_) => {}
-			Err(e) => {
-				eprintln!("Debug server failed to send message: {}", e);
-				self.stream = None;
+		for output in std::mem::take(&mut self.pending_output) {
+			self.broadcast(Response::Output { output });
+		}
+	}
+
+	// Drop every client whose reader thread has reported the socket gone. The reader
+	// reliably sees EOF / socket errors (it's blocked in `read`), so this is a real
+	// liveness signal rather than a probe write that a live peer never has to answer.
+	// Unlike `flush_output` this runs regardless of whether any telemetry is queued, so a
+	// client that vanished silently can't keep the pause loop blocked forever.
+	fn prune_dead_connections(&mut self) {
+		while let Ok(connection_id) = self.disconnects.try_recv() {
+			if self.connections.remove(&connection_id).is_some() {
+				eprintln!("Debug server dropping disconnected client {}", connection_id);
 			}
 		}
 	}
 
-	fn send(&mut self, response: Response) -> Result<(), Box<dyn std::error::Error>> {
-		let mut message = serde_json::to_vec(&response)?;
-		let stream = self.stream.as_mut().unwrap();
-		message.push(0); // null-terminator
-		stream.write_all(&message[..])?;
+	// Fan a response out to every attached client, used for stop and output events.
+	fn broadcast(&mut self, response: Response) {
+		let connection_ids: Vec<ConnectionId> = self.connections.keys().copied().collect();
+		for connection_id in connection_ids {
+			self.send_to(connection_id, None, response.clone());
+		}
+	}
+
+	// Send to a single connection, dropping just that one if its socket has gone away
+	// so a dead client can't take the others down with it. `seq` is the request this
+	// answers, or `None` for an unsolicited event.
+	fn send_to(&mut self, connection_id: ConnectionId, seq: Option<u64>, response: Response) {
+		let connection = match self.connections.get_mut(&connection_id) {
+			Some(connection) => connection,
+			None => return,
+		};
+
+		if let Err(e) = Self::send(connection, seq, &response) {
+			eprintln!("Debug server failed to send message: {}", e);
+			self.connections.remove(&connection_id);
+		}
+	}
+
+	fn send(
+		connection: &mut Connection,
+		seq: Option<u64>,
+		response: &Response,
+	) -> Result<(), Box<dyn std::error::Error>> {
+		let stream = &mut connection.stream;
+
+		match connection.framing {
+			// The legacy native client speaks the baseline protocol: a bare `Response`
+			// JSON object terminated by a null byte, with no `seq` envelope. Keep that shape
+			// byte-for-byte so existing clients keep working - the routing `seq` is internal
+			// and only travels on the DAP path below.
+			Framing::NullTerminated => {
+				let message = serde_json::to_vec(response)?;
+				stream.write_all(&message[..])?;
+				stream.write_all(&[0])?; // null-terminator
+			}
+
+			// DAP-framed clients get the `ResponseMessage` envelope (carrying `seq`) inside
+			// the `Content-Length` frame.
+			Framing::ContentLength => {
+				let message = serde_json::to_vec(&ResponseMessage {
+					seq,
+					response: response.clone(),
+				})?;
+				let header = format!("Content-Length: {}\r\n\r\n", message.len());
+				stream.write_all(header.as_bytes())?;
+				stream.write_all(&message[..])?;
+			}
+		}
+
 		stream.flush()?;
 		Ok(())
 	}
 }
 
 impl ServerThread {
-	fn start_thread(mut self) -> JoinHandle<()> {
-		thread::spawn(move || match self.listener.accept() {
-			Ok((stream, _)) => {
-				self.stream = Some(stream);
-				self.run();
-			}
+	fn start_thread(self) -> JoinHandle<()> {
+		thread::spawn(move || self.run())
+	}
 
-			Err(e) => {
-				println!("Debug server failed to accept connection {}", e);
+	// Keep accepting connections for the lifetime of the server, handing each one off
+	// to its own reader thread so a single dropped socket no longer stops everything.
+	fn run(self) {
+		let next_seq = Arc::new(AtomicU64::new(0));
+		let mut next_connection_id: ConnectionId = 0;
+
+		loop {
+			match self.listener.accept() {
+				Ok((stream, _)) => {
+					let reader = ConnectionReader {
+						connection_id: next_connection_id,
+						next_seq: next_seq.clone(),
+						connection: self.connection.clone(),
+						disconnect: self.disconnect.clone(),
+						requests: self.requests.clone(),
+						stream,
+					};
+
+					next_connection_id += 1;
+					thread::spawn(move || reader.run());
+				}
+
+				Err(e) => {
+					println!("Debug server failed to accept connection {}", e);
+					return;
+				}
 			}
-		})
+		}
 	}
+}
 
-	fn handle_request(&mut self, data: &[u8]) -> Result<(), Box<dyn Error>> {
+impl ConnectionReader {
+	fn handle_request(&self, data: &[u8]) -> Result<(), Box<dyn Error>> {
 		let request = serde_json::from_slice::<Request>(data)?;
-		self.requests.send(request)?;
+		self.requests.send(IncomingRequest {
+			connection_id: self.connection_id,
+			seq: self.next_seq.fetch_add(1, Ordering::Relaxed),
+			request,
+		})?;
 		Ok(())
 	}
 
 	fn run(mut self) {
+		let mut buf = [0u8; 4096];
+		let mut queued_data = vec![];
+
+		// Read until we've seen enough to tell which framing the client is using, then
+		// hand the Server a cloned stream tagged with that framing. A single TCP read can
+		// deliver fewer bytes than the `Content-Length` header is long, so we decide as
+		// soon as the buffered prefix can no longer be a prefix of that header rather than
+		// waiting for a fixed byte count — otherwise a short null-terminated first request
+		// like `"Pause"` would block here and deadlock the connection.
+		let framing = loop {
+			match self.stream.read(&mut buf) {
+				Ok(0) => return,
+
+				Ok(n) => {
+					queued_data.extend_from_slice(&buf[..n]);
+					if let Some(framing) = Framing::detect(&queued_data) {
+						break framing;
+					}
+				}
+
+				Err(e) => {
+					eprintln!("Debug server thread read error: {}", e);
+					return;
+				}
+			}
+		};
+
 		match self
 			.connection
-			.send(self.stream.as_mut().unwrap().try_clone().unwrap())
+			.send((self.connection_id, self.stream.try_clone().unwrap(), framing))
 		{
 			Ok(_) => {}
 			Err(e) => {
@@ -466,13 +1197,14 @@ impl ServerThread {
 			}
 		}
 
-		let mut buf = [0u8; 4096];
-		let mut queued_data = vec![];
-
-		// The incoming stream is JSON objects separated by null terminators.
 		loop {
-			match self.stream.as_mut().unwrap().read(&mut buf) {
-				Ok(0) => return,
+			if let Err(e) = self.drain_requests(&mut queued_data, framing) {
+				eprintln!("Debug server thread failed to handle request: {}", e);
+				break;
+			}
+
+			match self.stream.read(&mut buf) {
+				Ok(0) => break,
 
 				Ok(n) => {
 					queued_data.extend_from_slice(&buf[..n]);
@@ -480,30 +1212,255 @@ impl ServerThread {
 
 				Err(e) => {
 					eprintln!("Debug server thread read error: {}", e);
-					return;
+					break;
 				}
 			}
+		}
+
+		// The socket is gone; tell the Server so it drops this connection and, if it was
+		// the last one, stops waiting on it while paused.
+		let _ = self.disconnect.send(self.connection_id);
+	}
 
-			for message in queued_data.split(|x| *x == 0) {
-				// split can give us empty slices
-				if message.is_empty() {
-					continue;
+	// Pull every complete message out of `queued_data`, leaving any partial trailing
+	// message in place for the next read.
+	fn drain_requests(&self, queued_data: &mut Vec<u8>, framing: Framing) -> Result<(), Box<dyn Error>> {
+		match framing {
+			// JSON objects separated by null terminators.
+			Framing::NullTerminated => {
+				let mut consumed = 0;
+
+				while let Some(idx) = queued_data[consumed..].iter().position(|x| *x == 0) {
+					let message = &queued_data[consumed..consumed + idx];
+					if !message.is_empty() {
+						self.handle_request(message)?;
+					}
+					consumed += idx + 1;
 				}
 
-				match self.handle_request(message) {
-					Ok(_) => {}
+				queued_data.drain(..consumed);
+			}
 
-					Err(e) => {
-						eprintln!("Debug server thread failed to handle request: {}", e);
-						return;
+			// DAP `Content-Length: <n>\r\n\r\n<json>` framing.
+			Framing::ContentLength => {
+				loop {
+					let separator = match find_subsequence(queued_data, b"\r\n\r\n") {
+						Some(index) => index,
+						None => break,
+					};
+
+					let length = match parse_content_length(&queued_data[..separator]) {
+						Some(length) => length,
+						None => {
+							return Err("malformed DAP header: missing Content-Length".into());
+						}
+					};
+
+					let body_start = separator + 4;
+					if queued_data.len() < body_start + length {
+						// Haven't received the whole body yet.
+						break;
 					}
+
+					self.handle_request(&queued_data[body_start..body_start + length])?;
+					queued_data.drain(..body_start + length);
 				}
 			}
+		}
+
+		Ok(())
+	}
+}
+
+// A human-readable type name derived from the value's `ValueTag`, surfaced to the
+// client so the variables view can show what each entry actually is.
+fn value_kind(value: &Value) -> String {
+	use raw_types::values::ValueTag;
+
+	let tag = unsafe { value.value.tag };
+	match tag {
+		ValueTag::Null => "Null".to_owned(),
+		ValueTag::Number => "Number".to_owned(),
+		ValueTag::String => "String".to_owned(),
+		ValueTag::List => "List".to_owned(),
+		ValueTag::Mob => "Mob".to_owned(),
+		ValueTag::Obj => "Obj".to_owned(),
+		ValueTag::Turf => "Turf".to_owned(),
+		ValueTag::Area => "Area".to_owned(),
+		ValueTag::Datum => "Datum".to_owned(),
+		ValueTag::World => "World".to_owned(),
+		_ => format!("{:?}", tag),
+	}
+}
+
+// Whether a value has anything to drill into: lists expand into elements, datums and
+// atoms into their `vars` table.
+fn value_is_expandable(value: &Value) -> bool {
+	use raw_types::values::ValueTag;
+
+	matches!(
+		unsafe { value.value.tag },
+		ValueTag::List
+			| ValueTag::Datum | ValueTag::Obj
+			| ValueTag::Mob | ValueTag::Turf
+			| ValueTag::Area | ValueTag::World
+	)
+}
+
+// DM truthiness: a non-zero number, or any non-null reference/non-empty value.
+fn value_is_truthy(value: &Value) -> bool {
+	match value.as_number() {
+		Ok(number) => number != 0.0,
+		Err(_) => match value.as_string() {
+			Ok(string) => !string.is_empty(),
+			Err(_) => unsafe { value.value.tag != raw_types::values::ValueTag::Null },
+		},
+	}
+}
+
+// Expand every `{expr}` in `message`, rendering each expression with `eval`. An
+// unterminated `{` is emitted verbatim. Kept free of frame state so it can be tested
+// on its own.
+fn interpolate_with(message: &str, mut eval: impl FnMut(&str) -> String) -> String {
+	let mut output = String::new();
+	let mut rest = message;
+
+	while let Some(open) = rest.find('{') {
+		output.push_str(&rest[..open]);
+		let after = &rest[open + 1..];
+
+		match after.find('}') {
+			Some(close) => {
+				output.push_str(&eval(&after[..close]));
+				rest = &after[close + 1..];
+			}
+
+			// Unterminated `{` - emit the remainder verbatim.
+			None => {
+				output.push('{');
+				rest = after;
+			}
+		}
+	}
+
+	output.push_str(rest);
+	output
+}
+
+fn find_subsequence(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	haystack
+		.windows(needle.len())
+		.position(|window| window == needle)
+}
 
-			// Clear any finished messages from the buffer
-			if let Some(idx) = queued_data.iter().rposition(|x| *x == 0) {
-				queued_data.drain(..idx);
+fn parse_content_length(header: &[u8]) -> Option<usize> {
+	let header = std::str::from_utf8(header).ok()?;
+
+	for field in header.split("\r\n") {
+		if let Some((name, value)) = field.split_once(':') {
+			if name.trim().eq_ignore_ascii_case("Content-Length") {
+				return value.trim().parse().ok();
 			}
 		}
 	}
+
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn framing_detection() {
+		assert_eq!(
+			Framing::detect(b"Content-Length: 10\r\n\r\n{}"),
+			Some(Framing::ContentLength)
+		);
+		assert_eq!(
+			Framing::detect(b"{\"Pause\":null}\0"),
+			Some(Framing::NullTerminated)
+		);
+		// A short null-terminated first request is decided on its very first byte
+		// rather than waiting for the header to be spellable.
+		assert_eq!(Framing::detect(b"\"Pause\""), Some(Framing::NullTerminated));
+		// A proper prefix of the header stays ambiguous until more bytes arrive.
+		assert_eq!(Framing::detect(b"Content-"), None);
+		assert_eq!(Framing::detect(b""), None);
+	}
+
+	#[test]
+	fn content_length_parsing() {
+		assert_eq!(parse_content_length(b"Content-Length: 42"), Some(42));
+		assert_eq!(parse_content_length(b"content-length:7"), Some(7));
+		assert_eq!(
+			parse_content_length(b"Content-Type: application/json\r\nContent-Length: 9"),
+			Some(9)
+		);
+		assert_eq!(parse_content_length(b"Content-Type: text/plain"), None);
+		assert_eq!(parse_content_length(b"Content-Length: not-a-number"), None);
+	}
+
+	#[test]
+	fn subsequence_search() {
+		assert_eq!(find_subsequence(b"abc\r\n\r\nbody", b"\r\n\r\n"), Some(3));
+		assert_eq!(find_subsequence(b"no header separator", b"\r\n\r\n"), None);
+		assert_eq!(find_subsequence(b"", b"\r\n\r\n"), None);
+	}
+
+	#[test]
+	fn hit_condition_predicates() {
+		let cases = [
+			(">5", 6, true),
+			(">5", 5, false),
+			(">=3", 3, true),
+			(">=3", 2, false),
+			("<3", 2, true),
+			("<3", 3, false),
+			("==3", 3, true),
+			("==3", 4, false),
+			("%2", 4, true),
+			("%2", 3, false),
+			// A bare number behaves like `==n`.
+			("3", 3, true),
+			("3", 2, false),
+		];
+
+		for (source, hits, expected) in cases {
+			let condition = HitCondition::parse(source).expect("should parse");
+			assert_eq!(condition.is_satisfied(hits), expected, "{} @ {} hits", source, hits);
+		}
+	}
+
+	#[test]
+	fn hit_condition_modulo_zero_never_matches() {
+		let condition = HitCondition::parse("%0").expect("should parse");
+		assert!(!condition.is_satisfied(0));
+		assert!(!condition.is_satisfied(4));
+	}
+
+	#[test]
+	fn hit_condition_rejects_garbage() {
+		assert!(HitCondition::parse("nonsense").is_none());
+		assert!(HitCondition::parse(">").is_none());
+		assert!(HitCondition::parse("").is_none());
+	}
+
+	#[test]
+	fn logpoint_interpolation_expands_expressions() {
+		let rendered = interpolate_with("hp={health} of {max}", |expr| format!("<{}>", expr));
+		assert_eq!(rendered, "hp=<health> of <max>");
+	}
+
+	#[test]
+	fn logpoint_interpolation_keeps_unterminated_brace() {
+		let rendered = interpolate_with("oops {unterminated", |_| "X".to_owned());
+		assert_eq!(rendered, "oops {unterminated");
+	}
+
+	#[test]
+	fn logpoint_interpolation_passes_through_plain_text() {
+		let rendered = interpolate_with("no expressions here", |_| unreachable!());
+		assert_eq!(rendered, "no expressions here");
+	}
 }
@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProcRef {
+	pub path: String,
+	pub override_id: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InstructionRef {
+	pub proc: ProcRef,
+	pub offset: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StackFrame {
+	pub instruction: InstructionRef,
+	pub line: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum VariablesRef {
+	Arguments { frame: u16 },
+	Locals { frame: u16 },
+	Internal { tag: u8, data: u32 },
+}
+
+// One paused execution context, as presented to the client. `id` 0 is the context
+// that tripped the breakpoint; higher ids are sleeping/spawned contexts still alive.
+//
+// NOTE: this is a first cut that only enumerates the suspended contexts by id/name. The
+// `WaitRequest`-style saved state the thread model ultimately wants — what each context
+// is blocked on and its optional wake time — isn't surfaced yet, because BYOND doesn't
+// expose a suspended context's wait reason or resume deadline to us, so clients can see
+// *that* a green-thread is paused but not *why* or *until when*.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ThreadInfo {
+	pub id: u32,
+	pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Variable {
+	pub name: String,
+	pub kind: String,
+	pub value: String,
+	pub variables: Option<VariablesRef>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum BreakpointSetResult {
+	Success { line: Option<u32> },
+	Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum BreakpointReason {
+	Breakpoint,
+	Step,
+	Pause,
+	Runtime(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum ContinueKind {
+	Continue,
+	StepOver { stack_id: u32 },
+	StepInto { stack_id: u32 },
+	StepOut { stack_id: u32 },
+}
+
+// What this backend can do, handed to the client in response to `Request::Initialize`.
+// Field names follow the Debug Adapter Protocol's `Capabilities` object so a DAP
+// client can consume them verbatim.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+	pub supports_conditional_breakpoints: bool,
+	pub supports_hit_conditional_breakpoints: bool,
+	pub supports_log_points: bool,
+	pub supports_evaluate_for_hovers: bool,
+	pub supports_set_variable: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Request {
+	Initialize,
+
+	BreakpointSet {
+		instruction: InstructionRef,
+
+		// A condition gating whether the breakpoint actually stops. Evaluated in the scope
+		// of the frame that tripped it; only a truthy result breaks. NOTE: without BYOND's
+		// compiler this resolves a dotted variable path only (`health`, `src.hp`), not an
+		// arbitrary DM expression — a condition with a call/operator/index (`health < 50`)
+		// can't be evaluated and is treated as "never break", so the breakpoint silently
+		// stops firing. DAP clients are kept from sending these via
+		// `supports_conditional_breakpoints: false`; the native client should avoid them too.
+		#[serde(default)]
+		condition: Option<String>,
+
+		// A hit-count predicate like `>5`, `==3` or `%2`. The breakpoint only stops on
+		// the hits that satisfy it.
+		#[serde(default)]
+		hit_condition: Option<String>,
+
+		// When set, the breakpoint never stops; instead this message is emitted as an
+		// `Output` line, with any `{expr}` substrings evaluated and interpolated.
+		#[serde(default)]
+		log_message: Option<String>,
+	},
+
+	BreakpointUnset {
+		instruction: InstructionRef,
+	},
+
+	LineNumber {
+		proc: ProcRef,
+		offset: u32,
+	},
+
+	Offset {
+		proc: ProcRef,
+		line: u32,
+	},
+
+	StackFrames {
+		thread_id: u32,
+		start_frame: Option<u32>,
+		count: Option<u32>,
+	},
+
+	Threads,
+
+	Scopes {
+		#[serde(default)]
+		thread_id: u32,
+		frame_id: u32,
+	},
+
+	Variables {
+		vars: VariablesRef,
+
+		// Window into a potentially huge list or var table, mirroring the paging on
+		// `StackFrames`. `None` means "all of it".
+		#[serde(default)]
+		start: Option<u32>,
+		#[serde(default)]
+		count: Option<u32>,
+	},
+
+	// Inspect a value in a frame's scope. NOTE: despite the DAP name, `expression` is NOT
+	// compiled as DM — without BYOND's compiler this resolves a dotted variable path only
+	// (`health`, `src.loc.name`, `usr`), so any expression with a call, operator or index
+	// comes back as a runtime error. It powers variable/field watches and hover-eval, not a
+	// general REPL console.
+	Evaluate {
+		frame_id: u32,
+		expression: String,
+
+		// The DAP evaluation context the expression came from (`watch`, `repl`,
+		// `hover`, ...). Informational for now.
+		#[serde(default)]
+		context: Option<String>,
+	},
+
+	Continue {
+		kind: ContinueKind,
+	},
+
+	// Tune how the paused world behaves. `pause_timeout_ms` bounds how long the pause
+	// loop blocks waiting for a request before servicing periodic work; `None` keeps
+	// the old block-forever behavior.
+	Configure {
+		#[serde(default)]
+		pause_timeout_ms: Option<u64>,
+	},
+
+	Pause,
+}
+
+// A response as it goes out on the wire. `seq` echoes the server-assigned sequence of
+// the request this answers; unsolicited events (stop, output) carry no `seq`. NOTE: the
+// sequence is allocated by the server's connection reader, not sent by the client, so
+// it identifies the request only within the server's own routing — a client cannot use
+// it to correlate a reply to a request it issued (it never saw the number). It is
+// retained on the wire for diagnostics and so a future protocol revision can let the
+// client supply its own id.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseMessage {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub seq: Option<u64>,
+	pub response: Response,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Response {
+	Initialized {
+		capabilities: Capabilities,
+	},
+
+	BreakpointSet {
+		result: BreakpointSetResult,
+	},
+
+	BreakpointUnset {
+		success: bool,
+	},
+
+	LineNumber {
+		line: Option<u32>,
+	},
+
+	Offset {
+		offset: Option<u32>,
+	},
+
+	StackFrames {
+		frames: Vec<StackFrame>,
+		total_count: u32,
+	},
+
+	Threads {
+		threads: Vec<ThreadInfo>,
+	},
+
+	Scopes {
+		arguments: Option<VariablesRef>,
+		locals: Option<VariablesRef>,
+		globals: Option<VariablesRef>,
+	},
+
+	Variables {
+		vars: Vec<Variable>,
+	},
+
+	Evaluate {
+		value: String,
+		variables_ref: Option<VariablesRef>,
+	},
+
+	BreakpointHit {
+		reason: BreakpointReason,
+	},
+
+	Output {
+		output: String,
+	},
+}